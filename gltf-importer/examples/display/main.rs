@@ -16,7 +16,7 @@ fn main() {
     if let Some(path) = std::env::args().nth(1) {
         let import = Import::from_path(&path);
         match import.sync() {
-            Ok(gltf) => println!("{:#?}", gltf),
+            Ok(imported) => println!("{:#?}", imported.gltf),
             Err(err) => println!("Invalid glTF ({:?})", err),
         }
     } else {
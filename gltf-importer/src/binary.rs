@@ -0,0 +1,114 @@
+
+// Copyright 2017 The gltf Library Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use config::Config;
+use futures::future;
+use futures::Future;
+use standard;
+
+use {Error, ImportData, Source};
+
+/// The header magic value that begins every binary glTF asset.
+const MAGIC: [u8; 4] = *b"glTF";
+
+/// Chunk type value for the mandatory JSON chunk.
+const JSON_CHUNK_TYPE: u32 = 0x4E4F534A;
+
+/// Chunk type value for the optional binary chunk.
+const BIN_CHUNK_TYPE: u32 = 0x004E4942;
+
+/// The 12-byte header that begins a binary glTF asset.
+struct Header {
+    /// The glTF version this asset was exported with.
+    version: u32,
+
+    /// Total length of the binary glTF asset, including the header.
+    length: u32,
+}
+
+/// A single length-prefixed chunk of a binary glTF asset.
+struct Chunk {
+    /// The chunk type, e.g. `JSON_CHUNK_TYPE` or `BIN_CHUNK_TYPE`.
+    ty: u32,
+
+    /// The chunk payload.
+    data: Box<[u8]>,
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from(data[offset])
+        | (u32::from(data[offset + 1]) << 8)
+        | (u32::from(data[offset + 2]) << 16)
+        | (u32::from(data[offset + 3]) << 24)
+}
+
+fn read_header<S: Source>(data: &[u8]) -> Result<Header, Error<S>> {
+    if data.len() < 12 || data[0..4] != MAGIC {
+        return Err(Error::MalformedGlb("asset does not start with glTF magic".to_string()));
+    }
+    Ok(Header {
+        version: read_u32(data, 4),
+        length: read_u32(data, 8),
+    })
+}
+
+fn read_chunk<S: Source>(data: &[u8], offset: usize) -> Result<(Chunk, usize), Error<S>> {
+    if data.len() < offset + 8 {
+        return Err(Error::MalformedGlb("unexpected end of chunk header".to_string()));
+    }
+    let length = read_u32(data, offset) as usize;
+    let ty = read_u32(data, offset + 4);
+    let start = offset + 8;
+    let end = start + length;
+    if data.len() < end {
+        return Err(Error::MalformedGlb("chunk length exceeds asset length".to_string()));
+    }
+    let chunk = Chunk {
+        ty: ty,
+        data: data[start..end].to_vec().into_boxed_slice(),
+    };
+    Ok((chunk, end))
+}
+
+/// Splits a `.glb` asset into its mandatory JSON chunk and optional binary chunk.
+fn split<S: Source>(data: &[u8]) -> Result<(Box<[u8]>, Option<Box<[u8]>>), Error<S>> {
+    let header = read_header::<S>(data)?;
+    if header.version != 2 {
+        return Err(Error::IncompatibleVersion(header.version.to_string()));
+    }
+
+    let (json_chunk, offset) = read_chunk::<S>(data, 12)?;
+    if json_chunk.ty != JSON_CHUNK_TYPE {
+        return Err(Error::MalformedGlb("expected JSON chunk to come first".to_string()));
+    }
+
+    let bin_chunk = if offset < header.length as usize {
+        let (chunk, _) = read_chunk::<S>(data, offset)?;
+        if chunk.ty != BIN_CHUNK_TYPE {
+            return Err(Error::MalformedGlb("expected binary chunk".to_string()));
+        }
+        Some(chunk.data)
+    } else {
+        None
+    };
+
+    Ok((json_chunk.data, bin_chunk))
+}
+
+/// Imports a binary glTF (.glb) asset.
+pub fn import<S: Source>(
+    data: Box<[u8]>,
+    source: S,
+    config: Config,
+) -> Box<Future<Item = ImportData, Error = Error<S>>> {
+    match split::<S>(&data) {
+        Ok((json, bin_chunk)) => standard::import_impl(json, bin_chunk, source, config),
+        Err(err) => Box::new(future::err(err)),
+    }
+}
@@ -39,6 +39,9 @@ mod binary;
 /// Contains the implementation of the standard glTF importer.
 mod standard;
 
+/// Contains the `DataUri` helper for parsing `data:` URIs.
+mod uri;
+
 /// Contains data structures for import configuration.
 pub mod config;
 
@@ -52,6 +55,41 @@ pub use self::config::Config;
 pub use self::data::{Data, DynamicImage};
 pub use self::from_path::FromPath;
 
+/// The data resolved by a completed `Import`: the parsed glTF document
+/// together with the buffers and images it references.
+///
+/// Rather than reaching into the `buffers`/`images` vectors by raw index,
+/// use `buffer_data`/`view_data`/`image_data` to navigate from the document
+/// hierarchy straight to the backing bytes or pixels.
+pub struct ImportData {
+    /// The parsed glTF document.
+    pub gltf: Gltf,
+
+    /// Buffer data, in the same order as `gltf.buffers()`.
+    pub buffers: Vec<Data>,
+
+    /// Decoded image data, in the same order as `gltf.images()`.
+    pub images: Vec<DynamicImage>,
+}
+
+impl ImportData {
+    /// Returns the byte data of the given buffer.
+    pub fn buffer_data(&self, buffer: &gltf::Buffer) -> &[u8] {
+        &self.buffers[buffer.index()]
+    }
+
+    /// Returns the byte slice covered by the given buffer view.
+    pub fn view_data(&self, view: &gltf::buffer::View) -> &[u8] {
+        let data = &self.buffers[view.buffer().index()];
+        &data[view.offset()..(view.offset() + view.length())]
+    }
+
+    /// Returns the decoded pixel data of the given image.
+    pub fn image_data(&self, image: &gltf::Image) -> &DynamicImage {
+        &self.images[image.index()]
+    }
+}
+
 /// A trait for representing sources of glTF data that may be read by an importer.
 pub trait Source: Debug + Sized + 'static {
     /// User error type.
@@ -87,7 +125,11 @@ pub enum Error<S: Source> {
 
     /// Failure when deserializing .gltf or .glb JSON.
     MalformedJson(json::Error),
-    
+
+    /// An image embedded in a buffer view did not specify a supported
+    /// `mimeType`, so its format could not be determined.
+    UnsupportedImageEncoding(Option<String>),
+
     /// Data source error.
     Shared(future::SharedError<Error<S>>),
     
@@ -99,7 +141,7 @@ pub enum Error<S: Source> {
 }
 
 /// A `Future` that drives the importation of glTF.
-pub struct Import<S: Source>(Box<Future<Item = Gltf, Error = Error<S>>>);
+pub struct Import<S: Source>(Box<Future<Item = ImportData, Error = Error<S>>>);
 
 impl<S: Source> Import<S> {
     /// Constructs an `Import` from a custom `Source` and `Config` arguments.
@@ -119,7 +161,10 @@ impl<S: Source> Import<S> {
 
     /// Drives the import process to completion, blocking the current thread until
     /// complete.
-    pub fn sync(self) -> Result<Gltf, Error<S>> {
+    ///
+    /// On success, returns the parsed glTF document along with the buffers and
+    /// images it references.
+    pub fn sync(self) -> Result<ImportData, Error<S>> {
         self.wait()
     }
 }
@@ -133,7 +178,7 @@ impl Import<FromPath> {
 }
 
 impl<S: Source> Future for Import<S> {
-    type Item = Gltf;
+    type Item = ImportData;
     type Error = Error<S>;
     fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
         self.0.poll()
@@ -188,6 +233,7 @@ impl<S: Source> std::error::Error for Error<S> {
             &Io(_) => "I/O error",
             &MalformedGlb(_) => "Malformed .glb file",
             &MalformedJson(_) => "Malformed .gltf / .glb JSON",
+            &UnsupportedImageEncoding(_) => "Image format could not be determined",
             &Source(_) => "Data source error",
             &Shared(_) => "Shared error",
             &Validation(_) => "Asset failed validation tests",
@@ -19,12 +19,14 @@ use gltf::Gltf;
 use image::{load_from_memory, load_from_memory_with_format};
 use image::ImageFormat as Format;
 use image::ImageResult;
-use image::ImageFormat::{JPEG as Jpeg, PNG as Png};
+use image::ImageFormat::{JPEG as Jpeg, PNG as Png, GIF as Gif, BMP as Bmp, ICO as Ico, WEBP as WebP};
 use json::validation::Validate;
 use std::boxed::Box;
 use std::io::Cursor;
+use std::path::Path;
+use uri::{self, DataUri};
 
-use {Data, DynamicImage, Error, Source};
+use {Data, DynamicImage, Error, ImportData, Source};
 
 enum AsyncImage<S: Source> {
     /// Image data is borrowed from a buffer.
@@ -107,46 +109,104 @@ enum EncodedImage {
     },
 }
 
+/// Resolves the bytes named by a buffer/image `uri`. Inline `data:` URIs are
+/// decoded synchronously; anything else is percent-decoded and handed to the
+/// `Source` to load externally.
+fn source_uri<S: Source>(
+    source: &S,
+    uri: &str,
+) -> Box<Future<Item = Box<[u8]>, Error = S::Error>> {
+    match DataUri::parse(uri) {
+        Ok(data_uri) => Box::new(future::ok(data_uri.data.into_boxed_slice())),
+        Err(_) => {
+            let path = uri::percent_decode(uri);
+            Box::new(source.source_external_data(&path))
+        },
+    }
+}
+
 fn source_buffers<S: Source>(
     root: &Root,
     source: &S,
-) -> Vec<data::Async<S>> {
+    bin_chunk: Option<&[u8]>,
+) -> Result<Vec<data::Async<S>>, Error<S>> {
     root.as_json().buffers
         .iter()
         .map(|entry| {
-            let uri = entry.uri.as_ref().unwrap();
-            let future = Box::new(source.source_external_data(uri));
-            data::Async::full(future)
+            let future: Box<Future<Item = Box<[u8]>, Error = S::Error>> =
+                if let Some(uri) = entry.uri.as_ref() {
+                    source_uri(source, uri)
+                } else {
+                    // No URI means the buffer's data is embedded in the binary
+                    // chunk of a .glb file.
+                    let bin_chunk = bin_chunk.ok_or_else(|| {
+                        Error::MalformedGlb(
+                            "buffer has no uri and asset provides no binary chunk".to_string(),
+                        )
+                    })?;
+                    Box::new(future::ok(bin_chunk.to_vec().into_boxed_slice()))
+                };
+            Ok(data::Async::full(future))
         })
         .collect()
 }
 
+/// Maps a glTF `mimeType` string to the `image` crate format it names, if any.
+fn format_from_mime_type(mime_type: &str) -> Option<Format> {
+    match mime_type {
+        "image/jpeg" => Some(Jpeg),
+        "image/png" => Some(Png),
+        "image/gif" => Some(Gif),
+        "image/bmp" | "image/x-bmp" => Some(Bmp),
+        "image/x-icon" => Some(Ico),
+        "image/webp" => Some(WebP),
+        _ => None,
+    }
+}
+
+/// Infers an image format from a URI's file extension, if recognised.
+fn format_from_extension(uri: &str) -> Option<Format> {
+    Path::new(uri)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| match ext.to_lowercase().as_str() {
+            "jpg" | "jpeg" => Some(Jpeg),
+            "png" => Some(Png),
+            "gif" => Some(Gif),
+            "bmp" => Some(Bmp),
+            "ico" => Some(Ico),
+            "webp" => Some(WebP),
+            _ => None,
+        })
+}
+
 fn source_images<S: Source>(
     root: &Root,
     source: &S,
-) -> Vec<AsyncImage<S>> {
+) -> Result<Vec<AsyncImage<S>>, Error<S>> {
     root.as_json().images
         .iter()
         .map(|entry| {
-            let format = entry.mime_type.as_ref().map(|x| match x.0.as_str() {
-                "image/jpeg" => Jpeg,
-                "image/png" => Png,
-                _ => unreachable!(),
-            });
+            let mime_type = entry.mime_type.as_ref().map(|x| x.0.as_str());
+            let mime_format = mime_type.and_then(format_from_mime_type);
             if let Some(uri) = entry.uri.as_ref() {
-                let future = Box::new(source.source_external_data(uri));
-                AsyncImage::Owned {
+                let format = mime_format.or_else(|| format_from_extension(uri));
+                let future = source_uri(source, uri);
+                Ok(AsyncImage::Owned {
                     data: data::Async::full(future),
                     format: format,
-                }
+                })
             } else if let Some(index) = entry.buffer_view.as_ref() {
+                let format = mime_format.ok_or_else(|| {
+                    Error::UnsupportedImageEncoding(mime_type.map(str::to_string))
+                })?;
                 let buffer_view = &root.as_json().buffer_views[index.value()];
-                AsyncImage::Borrowed {
+                Ok(AsyncImage::Borrowed {
                     index: buffer_view.buffer.value(),
                     offset: buffer_view.byte_offset as usize,
                     len: buffer_view.byte_length as usize,
-                    format: format.unwrap(),
-                }
+                    format: format,
+                })
             } else {
                 unreachable!()
             }
@@ -181,7 +241,20 @@ pub fn import<S: Source>(
     data: Box<[u8]>,
     source: S,
     config: Config,
-) -> Box<Future<Item = Gltf, Error = Error<S>>> {
+) -> Box<Future<Item = ImportData, Error = Error<S>>> {
+    import_impl(data, None, source, config)
+}
+
+/// Imports a glTF document from its JSON bytes, optionally alongside the
+/// binary chunk of a `.glb` container that any URI-less buffers borrow their
+/// data from.
+pub fn import_impl<S: Source>(
+    json: Box<[u8]>,
+    bin_chunk: Option<Box<[u8]>>,
+    source: S,
+    config: Config,
+) -> Box<Future<Item = ImportData, Error = Error<S>>> {
+    let data = json;
     let task = future::lazy(move || {
         let data = data;
         match json::from_reader(Cursor::new(data)) {
@@ -225,21 +298,30 @@ pub fn import<S: Source>(
         })
         .and_then(move |root| {
             let source = source;
-            let buffers = source_buffers(&root, &source);
-            let images = source_images(&root, &source);
-            future::ok(root)
+            let buffers = match source_buffers(&root, &source, bin_chunk.as_ref().map(Box::as_ref)) {
+                Ok(buffers) => buffers,
+                Err(err) => return future::Either::B(future::err(err)),
+            };
+            let images = match source_images(&root, &source) {
+                Ok(images) => images,
+                Err(err) => return future::Either::B(future::err(err)),
+            };
+            future::Either::A(future::ok(root)
                 .join3(
                     future::join_all(buffers),
                     future::join_all(images),
-                )
+                ))
         })
         .and_then(|(root, buffers, images)| {
             let decoded_images = decode_images(&buffers, images)?;
             Ok((root, buffers, decoded_images))
         })
-        .and_then(|(root, _buffers, _images)| {
-            // TODO: Do something with the data!
-            Ok(Gltf::new(root))
+        .and_then(|(root, buffers, images)| {
+            Ok(ImportData {
+                gltf: Gltf::new(root),
+                buffers: buffers,
+                images: images,
+            })
         });
     Box::new(task)
 }
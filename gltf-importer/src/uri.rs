@@ -0,0 +1,102 @@
+
+// Copyright 2017 The gltf Library Developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use base64;
+
+/// The decoded payload of a `data:` URI.
+pub struct DataUri<'a> {
+    /// The MIME type specified in the URI's metadata, if any.
+    pub mime_type: Option<&'a str>,
+
+    /// The decoded payload bytes.
+    pub data: Vec<u8>,
+}
+
+/// Reasons a string failed to parse as a `data:` URI.
+#[derive(Clone, Debug)]
+pub enum Error {
+    /// The URI does not begin with the `data:` scheme.
+    Scheme,
+
+    /// The URI is missing the `,` that separates metadata from payload.
+    Format,
+
+    /// The payload claimed to be base64 but failed to decode as such.
+    Base64(base64::DecodeError),
+}
+
+impl<'a> DataUri<'a> {
+    /// Parses a URI of the form `data:[<mime>][;base64],<payload>`.
+    pub fn parse(uri: &'a str) -> Result<DataUri<'a>, Error> {
+        let rest = if uri.starts_with("data:") {
+            &uri["data:".len()..]
+        } else {
+            return Err(Error::Scheme);
+        };
+
+        let comma = rest.find(',').ok_or(Error::Format)?;
+        let (metadata, payload) = rest.split_at(comma);
+        let payload = &payload[1..];
+
+        let mut mime_type = None;
+        let mut is_base64 = false;
+        for token in metadata.split(';') {
+            if token == "base64" {
+                is_base64 = true;
+            } else if token.contains('/') {
+                mime_type = Some(token);
+            }
+        }
+
+        let data = if is_base64 {
+            base64::decode(payload).map_err(Error::Base64)?
+        } else {
+            decode_percent_bytes(payload)
+        };
+
+        Ok(DataUri { mime_type: mime_type, data: data })
+    }
+}
+
+fn hex_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'...b'9' => Some(byte - b'0'),
+        b'a'...b'f' => Some(byte - b'a' + 10),
+        b'A'...b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Percent-decodes `input`, leaving bytes that aren't part of a valid
+/// `%XX` escape untouched.
+///
+/// Operates on raw bytes rather than `str` slices, since an escape may be
+/// followed by bytes that don't fall on a UTF-8 character boundary.
+fn decode_percent_bytes(input: &str) -> Vec<u8> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let (Some(hi), Some(lo)) = (hex_value(bytes[i + 1]), hex_value(bytes[i + 2])) {
+                out.push((hi << 4) | lo);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    out
+}
+
+/// Percent-decodes a URI path, e.g. one naming an external file.
+pub fn percent_decode(uri: &str) -> String {
+    String::from_utf8_lossy(&decode_percent_bytes(uri)).into_owned()
+}